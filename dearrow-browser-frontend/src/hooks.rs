@@ -0,0 +1,42 @@
+use wasm_bindgen::JsCast;
+use web_sys::{window, HtmlDocument};
+use yew::prelude::*;
+
+/// A `UseStateHandle` that initializes from `document.cookie` on first render and writes
+/// itself back to `document.cookie` whenever it changes, so the value survives reloads.
+pub fn use_cookie_state<T, FTo, FFrom>(name: &'static str, default: T, to_str: FTo, from_str: FFrom) -> UseStateHandle<T>
+where
+    T: PartialEq + Clone + 'static,
+    FTo: Fn(&T) -> String + 'static,
+    FFrom: FnOnce(&str) -> Option<T>,
+{
+    let state = use_state(move || {
+        read_cookie(name).and_then(|value| from_str(&value)).unwrap_or(default)
+    });
+
+    {
+        let state = state.clone();
+        use_effect_with((*state).clone(), move |value| {
+            write_cookie(name, &to_str(value));
+            || ()
+        });
+    }
+
+    state
+}
+
+fn read_cookie(name: &str) -> Option<String> {
+    let document = window()?.document()?.dyn_into::<HtmlDocument>().ok()?;
+    let cookies = document.cookie().ok()?;
+    cookies.split(';').find_map(|pair| {
+        let (key, value) = pair.trim().split_once('=')?;
+        (key == name).then(|| value.to_string())
+    })
+}
+
+fn write_cookie(name: &str, value: &str) {
+    let Some(document) = window().and_then(|w| w.document()).and_then(|d| d.dyn_into::<HtmlDocument>().ok()) else {
+        return;
+    };
+    let _ = document.set_cookie(&format!("{name}={value}; path=/; max-age=31536000; samesite=lax"));
+}
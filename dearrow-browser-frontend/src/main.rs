@@ -1,17 +1,60 @@
 use std::rc::Rc;
 use chrono::{NaiveDateTime, Utc};
 use dearrow_browser_api::{StatusResponse, ApiThumbnail, ApiTitle};
+use js_sys::Array;
 use reqwest::Url;
+use serde::Deserialize;
 use strum::IntoStaticStr;
+use wasm_bindgen::{closure::Closure, JsCast};
 use yew::prelude::*;
 use yew_hooks::{use_async_with_options, UseAsyncOptions, use_interval};
 use yew_router::prelude::*;
-use web_sys::{window, HtmlInputElement};
+use web_sys::{window, Element, HtmlInputElement, IntersectionObserver, IntersectionObserverEntry, InputEvent};
 
 mod hooks;
-use hooks::use_async_suspension;
+use hooks::use_cookie_state;
 
 const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const PAGE_SIZE: u32 = 50;
+
+const YOUTUBE_VIDEO_ID_LEN: usize = 11;
+
+/// YouTube video IDs are always 11 characters from a base64url-ish alphabet.
+fn is_youtube_video_id(s: &str) -> bool {
+    s.len() == YOUTUBE_VIDEO_ID_LEN && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Normalizes user input into a bare YouTube video ID, accepting full URLs
+/// (`youtube.com/watch?v=`, `youtu.be/`, `/shorts/`, `/embed/`, `/live/`) as well as raw IDs.
+fn resolve_video_id(input: &str) -> String {
+    let Ok(url) = Url::parse(input) else {
+        return input.to_string();
+    };
+    let Some(host) = url.host_str() else {
+        return input.to_string();
+    };
+    let host = host.trim_start_matches("www.").trim_start_matches("m.");
+    let candidate = match host {
+        "youtu.be" => Some(url.path().trim_start_matches('/').to_string()),
+        "youtube.com" if url.path() == "/watch" => {
+            url.query_pairs().find(|(key, _)| key == "v").map(|(_, id)| id.to_string())
+        },
+        "youtube.com" => {
+            url.path()
+                .strip_prefix("/shorts/")
+                .or_else(|| url.path().strip_prefix("/embed/"))
+                .or_else(|| url.path().strip_prefix("/live/"))
+                .map(ToString::to_string)
+        },
+        _ => None,
+    };
+    // Only trust what we extracted from a recognized URL shape if it actually looks like a
+    // video ID; otherwise fall back to the raw input rather than e.g. a stray path segment.
+    match candidate {
+        Some(id) if is_youtube_video_id(&id) => id,
+        _ => input.to_string(),
+    }
+}
 
 #[derive(Clone, Routable, PartialEq, IntoStaticStr)]
 enum Route {
@@ -21,6 +64,8 @@ enum Route {
     Video { id: String },
     #[at("/user_id/:id")]
     User { id: String },
+    #[at("/uuid/:id")]
+    Uuid { id: String },
     #[not_found]
     #[at("/404")]
     NotFound,
@@ -32,15 +77,52 @@ enum DetailType {
     Thumbnail,
 }
 
+impl DetailType {
+    fn to_cookie_str(&self) -> String {
+        match self {
+            DetailType::Title => "title".to_string(),
+            DetailType::Thumbnail => "thumbnail".to_string(),
+        }
+    }
+
+    fn from_cookie_str(value: &str) -> Option<Self> {
+        match value {
+            "title" => Some(DetailType::Title),
+            "thumbnail" => Some(DetailType::Thumbnail),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 struct WindowContext {
     origin: Url,
     logo_url: Option<AttrValue>,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone)]
 struct AppContext {
     last_updated: Option<i64>,
+    status_error: Option<Rc<anyhow::Error>>,
+    retry_status: Callback<()>,
+}
+
+impl PartialEq for AppContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.last_updated == other.last_updated
+            && match (&self.status_error, &other.status_error) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.retry_status == other.retry_status
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct PreferencesContext {
+    table_mode: UseStateHandle<DetailType>,
+    searchbar_visible: UseStateHandle<bool>,
 }
 
 #[function_component]
@@ -73,16 +155,28 @@ fn App() -> Html {
             status.run();
         }, 60*1000);
     }
-    let app_context = use_memo(|&last_updated| AppContext {
-        last_updated,
-    }, status.data.as_ref().map(|d| d.last_updated));
+    let retry_status = {
+        let status = status.clone();
+        Callback::from(move |()| status.run())
+    };
+    let app_context = Rc::new(AppContext {
+        last_updated: status.data.as_ref().map(|d| d.last_updated),
+        status_error: status.error.clone(),
+        retry_status,
+    });
+
+    let table_mode = use_cookie_state("table_mode", DetailType::Title, DetailType::to_cookie_str, DetailType::from_cookie_str);
+    let searchbar_visible = use_cookie_state("searchbar_visible", true, bool::to_string, |v| v.parse().ok());
+    let preferences = Rc::new(PreferencesContext { table_mode, searchbar_visible });
 
     html! {
         <ContextProvider<Rc<WindowContext>> context={window_context}>
         <ContextProvider<Rc<AppContext>> context={app_context}>
+        <ContextProvider<Rc<PreferencesContext>> context={preferences}>
             <BrowserRouter>
                 <Switch<Route> render={render_route} />
             </BrowserRouter>
+        </ContextProvider<Rc<PreferencesContext>>>
         </ContextProvider<Rc<AppContext>>>
         </ContextProvider<Rc<WindowContext>>>
     }
@@ -103,7 +197,8 @@ macro_rules! search_block {
 fn Header() -> Html {
     let navigator = use_navigator().expect("navigator should exist");
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
-    let searchbar_visible = use_state_eq(|| true);
+    let preferences: Rc<PreferencesContext> = use_context().expect("PreferencesContext should be defined");
+    let searchbar_visible = preferences.searchbar_visible.clone();
 
     let toggle_searchbar = { 
         let searchbar_visible = searchbar_visible.clone();
@@ -116,8 +211,9 @@ fn Header() -> Html {
         let searchbar_visible = searchbar_visible.clone();
         Callback::from(move |e: KeyboardEvent| {
             if e.key() == "Enter" {
+                let input: HtmlInputElement = e.target_unchecked_into();
                 searchbar_visible.set(false);
-                navigator.push(&Route::NotFound);
+                navigator.push(&Route::Uuid { id: input.value() });
             }
         })
     };
@@ -139,7 +235,7 @@ fn Header() -> Html {
             if e.key() == "Enter" {
                 let input: HtmlInputElement = e.target_unchecked_into();
                 searchbar_visible.set(false);
-                navigator.push(&Route::Video { id: input.value() });
+                navigator.push(&Route::Video { id: resolve_video_id(&input.value()) });
             }
         })
     };
@@ -183,6 +279,13 @@ fn Footer() -> Html {
     html! {
         <div id="footer">
             <span>{last_updated}</span>
+            if let Some(err) = &app_context.status_error {
+                <span class="error-inline">
+                    {format!("Failed to refresh status: {err:#}")}
+                    {" "}
+                    <button onclick={let retry = app_context.retry_status.clone(); move |_| retry.emit(())}>{"Retry"}</button>
+                </span>
+            }
             <span>
                 {"DeArrow Browser © mini_bomba 2023. Uses DeArrow data licensed under "}
                 <a href="https://creativecommons.org/licenses/by-nc-sa/4.0/">{"CC BY-NC-SA 4.0"}</a>
@@ -199,6 +302,7 @@ fn render_route(route: Route) -> Html {
         Route::Home => html! {<HomePage></HomePage>},
         Route::Video { ref id } => html! {<VideoPage videoid={id.clone()}></VideoPage>},
         Route::User { ref id } => html! {<UserPage userid={id.clone()}></UserPage>},
+        Route::Uuid { ref id } => html! {<UuidPage uuid={id.clone()}></UuidPage>},
         Route::NotFound => html! {
             <>
                 <h2>{"404 - Not found"}</h2>
@@ -256,11 +360,157 @@ struct DetailTableRendererProps {
     hide_videoid: Option<()>,
 }
 
+#[derive(Clone, PartialEq)]
 enum DetailList {
     Thumbnails(Vec<ApiThumbnail>),
     Titles(Vec<ApiTitle>),
 }
 
+impl DetailList {
+    fn empty_for(mode: DetailType) -> Self {
+        match mode {
+            DetailType::Title => DetailList::Titles(vec![]),
+            DetailType::Thumbnail => DetailList::Thumbnails(vec![]),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            DetailList::Titles(list) => list.len(),
+            DetailList::Thumbnails(list) => list.len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn extend(&mut self, page: DetailList) {
+        match (self, page) {
+            (DetailList::Titles(list), DetailList::Titles(page)) => list.extend(page),
+            (DetailList::Thumbnails(list), DetailList::Thumbnails(page)) => list.extend(page),
+            _ => {},
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Default)]
+struct DetailFilter {
+    locked_only: bool,
+    unverified_only: bool,
+    shadow_hidden_only: bool,
+    original_only: bool,
+    min_score: Option<i32>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortColumn {
+    Submitted,
+    Score,
+    Votes,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    fn flipped(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct DetailSort {
+    column: SortColumn,
+    direction: SortDirection,
+}
+
+impl Default for DetailSort {
+    fn default() -> Self {
+        DetailSort { column: SortColumn::Submitted, direction: SortDirection::Descending }
+    }
+}
+
+impl DetailSort {
+    fn toggled(self, column: SortColumn) -> Self {
+        if self.column == column {
+            DetailSort { column, direction: self.direction.flipped() }
+        } else {
+            DetailSort { column, direction: SortDirection::Descending }
+        }
+    }
+
+    fn indicator(self, column: SortColumn) -> &'static str {
+        if self.column != column {
+            return "";
+        }
+        match self.direction {
+            SortDirection::Ascending => " ▲",
+            SortDirection::Descending => " ▼",
+        }
+    }
+}
+
+fn apply_filter_sort_titles(list: &[ApiTitle], filter: DetailFilter, sort: DetailSort) -> Vec<&ApiTitle> {
+    let mut rows: Vec<&ApiTitle> = list.iter()
+        .filter(|t| !filter.locked_only || t.locked)
+        .filter(|t| !filter.unverified_only || t.unverified)
+        .filter(|t| !filter.shadow_hidden_only || t.shadow_hidden)
+        .filter(|t| !filter.original_only || t.original)
+        .filter(|t| filter.min_score.map_or(true, |min| t.score >= min))
+        .collect();
+    rows.sort_by(|a, b| {
+        let ordering = match sort.column {
+            SortColumn::Submitted => a.time_submitted.cmp(&b.time_submitted),
+            SortColumn::Score => a.score.cmp(&b.score),
+            SortColumn::Votes => a.votes.cmp(&b.votes),
+        };
+        match sort.direction {
+            SortDirection::Ascending => ordering,
+            SortDirection::Descending => ordering.reverse(),
+        }
+    });
+    rows
+}
+
+fn apply_filter_sort_thumbnails(list: &[ApiThumbnail], filter: DetailFilter, sort: DetailSort) -> Vec<&ApiThumbnail> {
+    let mut rows: Vec<&ApiThumbnail> = list.iter()
+        .filter(|t| !filter.locked_only || t.locked)
+        .filter(|t| !filter.shadow_hidden_only || t.shadow_hidden)
+        .filter(|t| !filter.original_only || t.original)
+        .filter(|t| filter.min_score.map_or(true, |min| t.votes >= min))
+        .collect();
+    rows.sort_by(|a, b| {
+        let ordering = match sort.column {
+            SortColumn::Submitted => a.time_submitted.cmp(&b.time_submitted),
+            SortColumn::Score | SortColumn::Votes => a.votes.cmp(&b.votes),
+        };
+        match sort.direction {
+            SortDirection::Ascending => ordering,
+            SortDirection::Descending => ordering.reverse(),
+        }
+    });
+    rows
+}
+
+async fn fetch_detail_page(url: Rc<Url>, mode: DetailType, offset: u32) -> Result<DetailList, anyhow::Error> {
+    let mut paged_url = (*url).clone();
+    paged_url.query_pairs_mut()
+        .append_pair("offset", &offset.to_string())
+        .append_pair("count", &PAGE_SIZE.to_string());
+    let request = reqwest::get(paged_url).await?;
+    Ok(match mode {
+        DetailType::Thumbnail => DetailList::Thumbnails(request.json().await?),
+        DetailType::Title => DetailList::Titles(request.json().await?),
+    })
+}
+
 fn title_score(title: &ApiTitle) -> Html {
     html! {
         <>
@@ -337,110 +587,392 @@ macro_rules! user_link {
     };
 }
 
+#[derive(Properties)]
+struct ErrorPanelProps {
+    error: Rc<anyhow::Error>,
+    url: AttrValue,
+    on_retry: Callback<()>,
+}
+
+impl PartialEq for ErrorPanelProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.error, &other.error) && self.url == other.url && self.on_retry == other.on_retry
+    }
+}
+
 #[function_component]
-fn DetailTableRenderer(props: &DetailTableRendererProps) -> HtmlResult {
+fn ErrorPanel(props: &ErrorPanelProps) -> Html {
+    html! {
+        <div class="error-panel">
+            <p><b>{"Failed to fetch details from the API"}</b></p>
+            <p class="error-message">{format!("{:#}", props.error)}</p>
+            <p class="error-url">{props.url.clone()}</p>
+            <button onclick={let on_retry = props.on_retry.clone(); move |_| on_retry.emit(())}>{"Retry"}</button>
+        </div>
+    }
+}
+
+#[function_component]
+fn DetailTableRenderer(props: &DetailTableRendererProps) -> Html {
     let app_context: Rc<AppContext> = use_context().expect("AppContext should be defined");
-    let details: Rc<Result<DetailList, anyhow::Error>> = use_async_suspension(|(mode, url, _)| async move {
-        let request = reqwest::get((*url).clone()).await?;
-        match mode {
-            DetailType::Thumbnail => Ok(DetailList::Thumbnails(request.json().await?)),
-            DetailType::Title => Ok(DetailList::Titles(request.json().await?)),
+    // Plain `use_state` isn't enough here: the reset effect below needs the *next* fetch to see
+    // offset 0 immediately, in the same tick, rather than waiting for the following render.
+    let next_offset = use_mut_ref(|| 0u32);
+    // Numbers each completed fetch, so a page equal to the previous one still counts as new data.
+    let fetch_count = use_mut_ref(|| 0u32);
+    let items = use_state(|| Rc::new(DetailList::empty_for(props.mode)));
+    let exhausted = use_state(|| false);
+
+    let page = {
+        let url = props.url.clone();
+        let mode = props.mode;
+        let next_offset = next_offset.clone();
+        let fetch_count = fetch_count.clone();
+        use_async_with_options::<_, (u32, DetailList), Rc<anyhow::Error>>(async move {
+            let offset = *next_offset.borrow();
+            let list = fetch_detail_page(url, mode, offset).await.map_err(Rc::new)?;
+            let mut count = fetch_count.borrow_mut();
+            *count = count.wrapping_add(1);
+            Ok((*count, list))
+        }, UseAsyncOptions::default())
+    };
+
+    // (Re)start the list from scratch whenever the mode, the source url or the dataset itself changes.
+    {
+        let items = items.clone();
+        let next_offset = next_offset.clone();
+        let exhausted = exhausted.clone();
+        let page = page.clone();
+        let mode = props.mode;
+        use_effect_with((props.mode, props.url.clone(), app_context.last_updated), move |_| {
+            items.set(Rc::new(DetailList::empty_for(mode)));
+            *next_offset.borrow_mut() = 0;
+            exhausted.set(false);
+            page.run();
+            || ()
+        });
+    }
+
+    // Append whatever the latest page fetch returned, and advance the offset for the next one.
+    {
+        let items = items.clone();
+        let next_offset = next_offset.clone();
+        let exhausted = exhausted.clone();
+        use_effect_with(page.data.clone(), move |data| {
+            if let Some((_, new_page)) = data {
+                if new_page.len() < PAGE_SIZE as usize {
+                    exhausted.set(true);
+                }
+                let mut merged = (*(*items)).clone();
+                let fetched = new_page.len();
+                merged.extend(new_page.clone());
+                items.set(Rc::new(merged));
+                *next_offset.borrow_mut() += fetched as u32;
+            }
+            || ()
+        });
+    }
+
+    let load_more = {
+        let page = page.clone();
+        let loading = page.loading;
+        let exhausted = *exhausted;
+        Callback::from(move |()| {
+            if !exhausted && !loading {
+                page.run();
+            }
+        })
+    };
+
+    // IntersectionObserver reads the latest load_more through this cell, so the observer
+    // itself only needs to be set up once per sentinel element.
+    let load_more_cell = use_mut_ref(Callback::noop);
+    *load_more_cell.borrow_mut() = load_more.clone();
+    let sentinel_ref = use_node_ref();
+    {
+        let sentinel_ref = sentinel_ref.clone();
+        // The sentinel leaves the DOM while the list is exhausted or the error panel is shown,
+        // and comes back as a new element, so the observer is set up again each time it reappears.
+        let sentinel_shown = !*exhausted && !(items.is_empty() && page.error.is_some());
+        use_effect_with((sentinel_ref.clone(), sentinel_shown), move |(sentinel_ref, _)| {
+            let Some(sentinel) = sentinel_ref.cast::<Element>() else {
+                return Box::new(|| ()) as Box<dyn FnOnce()>;
+            };
+            let observer_callback = Closure::<dyn FnMut(Array)>::new(move |entries: Array| {
+                let intersecting = entries.iter().any(|entry| {
+                    entry.unchecked_into::<IntersectionObserverEntry>().is_intersecting()
+                });
+                if intersecting {
+                    load_more_cell.borrow().emit(());
+                }
+            });
+            let observer = IntersectionObserver::new(observer_callback.as_ref().unchecked_ref())
+                .expect("should be able to create an IntersectionObserver");
+            observer.observe(&sentinel);
+            observer_callback.forget();
+            Box::new(move || observer.disconnect()) as Box<dyn FnOnce()>
+        });
+    }
+
+    let filter = use_state(DetailFilter::default);
+    let sort = use_state(DetailSort::default);
+    let set_sort = {
+        let sort = sort.clone();
+        Callback::from(move |column: SortColumn| sort.set(sort.toggled(column)))
+    };
+
+    if items.is_empty() {
+        if let Some(err) = &page.error {
+            let retry = {
+                let page = page.clone();
+                Callback::from(move |()| page.run())
+            };
+            return html! {
+                <ErrorPanel error={err.clone()} url={AttrValue::from((*props.url).to_string())} on_retry={retry} />
+            };
         }
-    }, (props.mode, props.url.clone(), app_context.last_updated))?;
+    }
 
-    Ok(match *details {
-        Err(..) => html! {
-            <center><b>{"Failed to fetch details from the API :/"}</b></center>
-        },
-        Ok(DetailList::Titles(ref list)) => html! {
-            <table class="detail-table titles">
-                <tr>
-                    <th>{"Submitted"}</th>
-                    if props.hide_videoid.is_none() {
-                        <th>{"Video ID"}</th>
-                    }
-                    <th>{"Title"}</th>
-                    <th>{"Score"}</th>
-                    <th>{"Votes"}</th>
-                    <th>{"UUID"}</th>
-                    if props.hide_userid.is_none() {
-                        <th>{"User ID"}</th>
+    html! {
+        <>
+            <FilterBar mode={props.mode} filter={filter.clone()} />
+            { detail_table(&items, props, *filter, *sort, &set_sort) }
+            if !*exhausted {
+                <div ref={sentinel_ref} class="load-more-sentinel">
+                    if let Some(err) = &page.error {
+                        <p class="error-inline">{ format!("Failed to load more: {err:#}") }</p>
                     }
-                </tr>
-                { for list.iter().map(|t| html! {
-                    <tr key={&*t.uuid}>
-                        <td>{NaiveDateTime::from_timestamp_millis(t.time_submitted).map_or(t.time_submitted.to_string(), |dt| format!("{}", dt.format(TIME_FORMAT)))}</td>
+                    <button onclick={let load_more = load_more.clone(); move |_| load_more.emit(())} disabled={page.loading}>
+                        { if page.loading { "Loading..." } else if page.error.is_some() { "Retry" } else { "Load more" } }
+                    </button>
+                </div>
+            }
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct FilterBarProps {
+    mode: DetailType,
+    filter: UseStateHandle<DetailFilter>,
+}
+
+#[function_component]
+fn FilterBar(props: &FilterBarProps) -> Html {
+    let filter = props.filter.clone();
+    let toggle = |set: fn(&mut DetailFilter, bool)| {
+        let filter = filter.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut new_filter = *filter;
+            set(&mut new_filter, input.checked());
+            filter.set(new_filter);
+        })
+    };
+    let set_min_score = {
+        let filter = filter.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut new_filter = *filter;
+            new_filter.min_score = input.value().parse().ok();
+            filter.set(new_filter);
+        })
+    };
+
+    html! {
+        <div class="filter-bar">
+            <label>
+                <input type="checkbox" checked={filter.locked_only} onchange={toggle(|f, v| f.locked_only = v)} />
+                {"Locked only"}
+            </label>
+            if props.mode == DetailType::Title {
+                <label>
+                    <input type="checkbox" checked={filter.unverified_only} onchange={toggle(|f, v| f.unverified_only = v)} />
+                    {"Unverified only"}
+                </label>
+            }
+            <label>
+                <input type="checkbox" checked={filter.shadow_hidden_only} onchange={toggle(|f, v| f.shadow_hidden_only = v)} />
+                {"Shadow-hidden only"}
+            </label>
+            <label>
+                <input type="checkbox" checked={filter.original_only} onchange={toggle(|f, v| f.original_only = v)} />
+                {"Original only"}
+            </label>
+            <label>
+                {"Minimum score"}
+                <input type="number" oninput={set_min_score} />
+            </label>
+        </div>
+    }
+}
+
+fn detail_table(list: &DetailList, props: &DetailTableRendererProps, filter: DetailFilter, sort: DetailSort, set_sort: &Callback<SortColumn>) -> Html {
+    match list {
+        DetailList::Titles(list) => {
+            let rows = apply_filter_sort_titles(list, filter, sort);
+            html! {
+                <table class="detail-table titles">
+                    <tr>
+                        <th class="sortable" onclick={let set_sort = set_sort.clone(); move |_| set_sort.emit(SortColumn::Submitted)}>{"Submitted"}{sort.indicator(SortColumn::Submitted)}</th>
                         if props.hide_videoid.is_none() {
-                            <td>{video_link!(t.video_id)}</td>
+                            <th>{"Video ID"}</th>
                         }
-                        <td>{t.title.clone()}{original_indicator!(t.original, title)}</td>
-                        <td>{title_score(t)}</td>
-                        <td>{t.votes}</td>
-                        <td>{t.uuid.clone()}</td>
+                        <th>{"Title"}</th>
+                        <th class="sortable" onclick={let set_sort = set_sort.clone(); move |_| set_sort.emit(SortColumn::Score)}>{"Score"}{sort.indicator(SortColumn::Score)}</th>
+                        <th class="sortable" onclick={let set_sort = set_sort.clone(); move |_| set_sort.emit(SortColumn::Votes)}>{"Votes"}{sort.indicator(SortColumn::Votes)}</th>
+                        <th>{"UUID"}</th>
                         if props.hide_userid.is_none() {
-                            <td>{user_link!(t.user_id)}</td>
+                            <th>{"User ID"}</th>
                         }
                     </tr>
-                }) }
-            </table>
+                    { for rows.into_iter().map(|t| html! {
+                        <tr key={&*t.uuid}>
+                            <td>{NaiveDateTime::from_timestamp_millis(t.time_submitted).map_or(t.time_submitted.to_string(), |dt| format!("{}", dt.format(TIME_FORMAT)))}</td>
+                            if props.hide_videoid.is_none() {
+                                <td>{video_link!(t.video_id)}</td>
+                            }
+                            <td>{t.title.clone()}{original_indicator!(t.original, title)}</td>
+                            <td>{title_score(t)}</td>
+                            <td>{t.votes}</td>
+                            <td>{t.uuid.clone()}</td>
+                            if props.hide_userid.is_none() {
+                                <td>{user_link!(t.user_id)}</td>
+                            }
+                        </tr>
+                    }) }
+                </table>
+            }
         },
-        Ok(DetailList::Thumbnails(ref list)) => html! {
-            <table class="detail-table thumbnails">
-                <tr>
-                    <th>{"Submitted"}</th>
-                    if props.hide_videoid.is_none() {
-                        <th>{"Video ID"}</th>
-                    }
-                    <th>{"Timestamp"}</th>
-                    <th>{"Score/Votes"}</th>
-                    <th>{"UUID"}</th>
-                    if props.hide_userid.is_none() {
-                        <th>{"User ID"}</th>
-                    }
-                </tr>
-                { for list.iter().map(|t| html! {
-                    <tr key={&*t.uuid}>
-                        <td>{NaiveDateTime::from_timestamp_millis(t.time_submitted).map_or(t.time_submitted.to_string(), |dt| format!("{}", dt.format(TIME_FORMAT)))}</td>
+        DetailList::Thumbnails(list) => {
+            let rows = apply_filter_sort_thumbnails(list, filter, sort);
+            html! {
+                <table class="detail-table thumbnails">
+                    <tr>
+                        <th class="sortable" onclick={let set_sort = set_sort.clone(); move |_| set_sort.emit(SortColumn::Submitted)}>{"Submitted"}{sort.indicator(SortColumn::Submitted)}</th>
                         if props.hide_videoid.is_none() {
-                            <td>{video_link!(t.video_id)}</td>
+                            <th>{"Video ID"}</th>
                         }
-                        <td>{t.timestamp.map_or(original_indicator!(t.original, thumbnail), |ts| html! {{ts.to_string()}})}</td>
-                        <td>{thumbnail_score(t)}</td>
-                        <td>{t.uuid.clone()}</td>
+                        <th>{"Timestamp"}</th>
+                        <th class="sortable" onclick={let set_sort = set_sort.clone(); move |_| set_sort.emit(SortColumn::Votes)}>{"Score/Votes"}{sort.indicator(SortColumn::Votes)}</th>
+                        <th>{"UUID"}</th>
                         if props.hide_userid.is_none() {
-                            <td>{user_link!(t.user_id)}</td>
+                            <th>{"User ID"}</th>
                         }
                     </tr>
-                }) }
-            </table>
+                    { for rows.into_iter().map(|t| html! {
+                        <tr key={&*t.uuid}>
+                            <td>{NaiveDateTime::from_timestamp_millis(t.time_submitted).map_or(t.time_submitted.to_string(), |dt| format!("{}", dt.format(TIME_FORMAT)))}</td>
+                            if props.hide_videoid.is_none() {
+                                <td>{video_link!(t.video_id)}</td>
+                            }
+                            <td>{t.timestamp.map_or(original_indicator!(t.original, thumbnail), |ts| html! {{ts.to_string()}})}</td>
+                            <td>{thumbnail_score(t)}</td>
+                            <td>{t.uuid.clone()}</td>
+                            if props.hide_userid.is_none() {
+                                <td>{user_link!(t.user_id)}</td>
+                            }
+                        </tr>
+                    }) }
+                </table>
+            }
         },
-    })
+    }
 }
 
 #[function_component]
 fn HomePage() -> Html {
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
-    let table_mode = use_state_eq(|| DetailType::Title);
+    let preferences: Rc<PreferencesContext> = use_context().expect("PreferencesContext should be defined");
+    let table_mode = preferences.table_mode.clone();
 
     let url = match *table_mode {
         DetailType::Title => window_context.origin.join("/api/titles"),
         DetailType::Thumbnail => window_context.origin.join("/api/thumbnails"),
     }.expect("Should be able to create an API url");
 
-    let fallback = html! {
-        <center><b>{"Loading..."}</b></center>
-    };
-    
     html! {
         <>
             <TableModeSwitch state={table_mode.clone()} />
-            <Suspense {fallback}>
-                <DetailTableRenderer mode={*table_mode} url={Rc::new(url)} />
-            </Suspense>
+            <DetailTableRenderer mode={*table_mode} url={Rc::new(url)} />
         </>
     }
 }
 
+#[derive(Clone, PartialEq, Deserialize)]
+struct YoutubeVideoMetadata {
+    title: String,
+    author: String,
+    length_seconds: u64,
+    thumbnail_url: String,
+    upload_date: String,
+}
+
+fn format_duration(total_seconds: u64) -> String {
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct VideoInfoCardProps {
+    videoid: AttrValue,
+}
+
+#[function_component]
+fn VideoInfoCard(props: &VideoInfoCardProps) -> Html {
+    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+
+    // `/api/youtube/video/:id` is expected to scrape the video's metadata from YouTube's watch
+    // page/innertube response server-side; no backend crate exists in this checkout to add that
+    // route to, so until it's added elsewhere this always falls through to the plain-ID fallback below.
+    let metadata = {
+        let window_context = window_context.clone();
+        let videoid = props.videoid.clone();
+        use_async_with_options::<_, YoutubeVideoMetadata, Rc<anyhow::Error>>(async move {
+            async {
+                let url = window_context.origin.join(&format!("/api/youtube/video/{videoid}"))?;
+                Ok(reqwest::get(url).await?.json().await?)
+            }.await.map_err(Rc::new)
+        }, UseAsyncOptions::default())
+    };
+
+    // Re-run the fetch when the videoid changes, since the router reuses this component instance across navigations.
+    {
+        let metadata = metadata.clone();
+        use_effect_with(props.videoid.clone(), move |_| {
+            metadata.run();
+            || ()
+        });
+    }
+
+    match &metadata.data {
+        Some(meta) => html! {
+            <div class="video-info-card">
+                <img src={meta.thumbnail_url.clone()} alt="Video thumbnail" />
+                <div>
+                    <h3>{meta.title.clone()}</h3>
+                    <span>{meta.author.clone()}</span>
+                    <span>{" • "}</span>
+                    <span>{meta.upload_date.clone()}</span>
+                    <span>{" • "}</span>
+                    <span>{format_duration(meta.length_seconds)}</span>
+                </div>
+            </div>
+        },
+        None => html! {
+            <div class="video-info-card">
+                <span>{props.videoid.clone()}</span>
+            </div>
+        },
+    }
+}
+
 #[derive(Properties, PartialEq)]
 struct VideoPageProps {
     videoid: AttrValue,
@@ -449,23 +981,19 @@ struct VideoPageProps {
 #[function_component]
 fn VideoPage(props: &VideoPageProps) -> Html {
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
-    let table_mode = use_state_eq(|| DetailType::Title);
+    let preferences: Rc<PreferencesContext> = use_context().expect("PreferencesContext should be defined");
+    let table_mode = preferences.table_mode.clone();
 
     let url = match *table_mode {
         DetailType::Title => window_context.origin.join(format!("/api/titles/video_id/{}", props.videoid).as_str()),
         DetailType::Thumbnail => window_context.origin.join(format!("/api/thumbnails/video_id/{}", props.videoid).as_str()),
     }.expect("Should be able to create an API url");
 
-    let fallback = html! {
-        <center><b>{"Loading..."}</b></center>
-    };
-    
     html! {
         <>
+            <VideoInfoCard videoid={props.videoid.clone()} />
             <TableModeSwitch state={table_mode.clone()} />
-            <Suspense {fallback}>
-                <DetailTableRenderer mode={*table_mode} url={Rc::new(url)} hide_videoid={()} />
-            </Suspense>
+            <DetailTableRenderer mode={*table_mode} url={Rc::new(url)} hide_videoid={()} />
         </>
     }
 }
@@ -478,27 +1006,77 @@ struct UserPageProps {
 #[function_component]
 fn UserPage(props: &UserPageProps) -> Html {
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
-    let table_mode = use_state_eq(|| DetailType::Title);
+    let preferences: Rc<PreferencesContext> = use_context().expect("PreferencesContext should be defined");
+    let table_mode = preferences.table_mode.clone();
 
     let url = match *table_mode {
         DetailType::Title => window_context.origin.join(format!("/api/titles/user_id/{}", props.userid).as_str()),
         DetailType::Thumbnail => window_context.origin.join(format!("/api/thumbnails/user_id/{}", props.userid).as_str()),
     }.expect("Should be able to create an API url");
 
-    let fallback = html! {
-        <center><b>{"Loading..."}</b></center>
-    };
-    
     html! {
         <>
             <TableModeSwitch state={table_mode.clone()} />
-            <Suspense {fallback}>
-                <DetailTableRenderer mode={*table_mode} url={Rc::new(url)} hide_userid={()} />
-            </Suspense>
+            <DetailTableRenderer mode={*table_mode} url={Rc::new(url)} hide_userid={()} />
         </>
     }
 }
 
+#[derive(Properties, PartialEq)]
+struct UuidPageProps {
+    uuid: AttrValue,
+}
+
+#[function_component]
+fn UuidPage(props: &UuidPageProps) -> Html {
+    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+
+    let detection = {
+        let window_context = window_context.clone();
+        let uuid = props.uuid.clone();
+        use_async_with_options::<_, DetailType, Rc<anyhow::Error>>(async move {
+            async {
+                let title_url = window_context.origin.join(&format!("/api/titles/uuid/{uuid}"))?;
+                if reqwest::get(title_url).await?.status().is_success() {
+                    return Ok(DetailType::Title);
+                }
+                let thumbnail_url = window_context.origin.join(&format!("/api/thumbnails/uuid/{uuid}"))?;
+                if reqwest::get(thumbnail_url).await?.status().is_success() {
+                    return Ok(DetailType::Thumbnail);
+                }
+                Err(anyhow::anyhow!("No title or thumbnail submission exists with this UUID"))
+            }.await.map_err(Rc::new)
+        }, UseAsyncOptions::default())
+    };
+
+    // Re-run detection when the uuid changes, since the router reuses this component instance across navigations.
+    {
+        let detection = detection.clone();
+        use_effect_with(props.uuid.clone(), move |_| {
+            detection.run();
+            || ()
+        });
+    }
+
+    match detection.data {
+        Some(mode) => {
+            let url = match mode {
+                DetailType::Title => window_context.origin.join(&format!("/api/titles/uuid/{}", props.uuid)),
+                DetailType::Thumbnail => window_context.origin.join(&format!("/api/thumbnails/uuid/{}", props.uuid)),
+            }.expect("Should be able to create an API url");
+
+            html! {
+                <DetailTableRenderer mode={mode} url={Rc::new(url)} />
+            }
+        },
+        None if detection.error.is_some() => html! {
+            <center><b>{"No submission found with this UUID"}</b></center>
+        },
+        None => html! {
+            <center><b>{"Loading..."}</b></center>
+        },
+    }
+}
 
 fn main() {
     yew::Renderer::<App>::new().render();